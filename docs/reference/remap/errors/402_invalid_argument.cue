@@ -0,0 +1,13 @@
+package metadata
+
+remap: errors: "402": {
+	title: "Invalid argument"
+	description: """
+		The argument is of the expected type, but the function can't make sense of its contents, such
+		as an unknown filter name passed to `redact`.
+		"""
+	resolution: """
+		Check the error message and the documentation for this function in the [VRL functions
+		reference](\(urls.vrl_functions)) to see which values are valid for this argument.
+		"""
+}
@@ -0,0 +1,541 @@
+package metadata
+
+remap: functions: redact: {
+	category: "String"
+	description: """
+		Redacts sensitive data in `value` such as credit card numbers or inline secrets.
+
+		`value` may be a string, in which case all matches are redacted, or an array or object, in which
+		case every string value is redacted recursively. Values of any other type are returned unchanged.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The value to redact sensitive data from."
+			required:    true
+			type: ["any"]
+		},
+		{
+			name: "filters"
+			description: #"""
+				List of filters applied to `value`.
+
+				Each filter can be specified in the following ways:
+
+				* As a regular expression, which is used to redact text that matches it.
+				* As an object with a `type` key that corresponds to a named filter and additional keys for customizing that filter.
+				* As a named filter, if it has no required parameters.
+
+				Named filters can be a:
+
+				* `pattern`: Redacts text matching any regular expressions specified in the `patterns`
+				   key, which is required. This is the expanded version of just passing a regular expression as a filter.
+				   Regular expressions with a capture group named `redact`, such as `token=(?P<redact>\\w+)`,
+				   only redact the text of that group, keeping the rest of the match. This also applies to
+				   regular expressions passed as filters directly.
+				   Setting the optional `fuzzy` key to `true` matches string patterns regardless of case and of
+				   whitespace inserted into the text, such as `S3CR3T p a s s` for `s3cr3tpass`. As this is
+				   prone to false positives, it's disabled by default.
+				   Setting the optional `case_sensitive` key to `false` matches string patterns regardless of
+				   case only, so `secret` also redacts `Secret` and `SECRET`. Regular expressions are
+				   unaffected, and can use the `(?i)` flag instead.
+				* `credit_card`: Redacts credit card numbers. Only numbers passing the Luhn checksum are
+				   redacted, leaving order IDs and tracking numbers of the same shape intact.
+				* `email`: Redacts email addresses, including plus-addressing and subdomains, such as
+				   `jane.doe+logs@mail.example.co.uk`.
+				* `us_ssn`: Redacts US Social Security Numbers, either dashed as in `123-45-6789` or not.
+				   Numbers that are never issued, such as those with the area numbers `000`, `666` or `9xx`,
+				   are left intact.
+				* `ip_address`: Redacts IPv4 addresses, leaving any port in place, and IPv6 addresses
+				   including compressed forms such as `2001:db8::1`. Version strings such as `1.2.3` are left
+				   intact.
+				* `iban`: Redacts International Bank Account Numbers, either unspaced or grouped by four as in
+				   `DE89 3704 0044 0532 0130 00`. Only numbers passing the mod-97 checksum are redacted,
+				   leaving other strings of the same shape intact.
+				* `inline_secret_phrase`: Redacts the value in natural-language phrases such as
+				   "the password is hunter2" or "token: abc123", leaving the phrase itself intact.
+				* `asset_tag`: Redacts hardware serial numbers and asset tags. Defaults to common Dell, HP and
+				   Lenovo serial number shapes, which can be replaced with custom formats through the optional
+				   `patterns` key.
+				* `multipart`: Redacts the contents of the `multipart/form-data` fields named in the
+				   `field_names` key, which is required. Part headers are kept intact.
+				* `graphql_variables`: Redacts the values in the `variables` object of a JSON-encoded GraphQL
+				   request whose names match common secret names such as `token` or `password`, leaving the
+				   query intact. The names can be replaced through the optional `patterns` key.
+				* `tracking_id`: Redacts analytics tracking IDs such as Google Analytics measurement IDs
+				   (`G-XXXXXXXXXX`, `UA-XXXX-Y`), UUID-shaped device advertising IDs (IDFA, GAID) and the
+				   values of `_ga` cookies.
+				* `data_uri`: Redacts the payload of `data:` URIs such as inline base64-encoded images. The
+				   media type is kept unless the optional `keep_mime` key is `false`.
+				* `sql_literal`: Redacts string and numeric literals in SQL statements, keeping the quotes
+				   of string literals. The optional `columns` key limits this to literals compared against
+				   columns matching any of the given patterns, such as `ssn = '123-45-6789'`.
+				* `insurance_id`: Redacts health insurance member IDs and group numbers. Defaults to IDs with an
+				   alphabetic payer prefix, Medicare Beneficiary Identifiers and labeled member, group or
+				   policy numbers. As these formats are payer-specific, they can be replaced through the
+				   optional `patterns` key.
+				* `internal_network`: Redacts IP addresses within any of the CIDR ranges in the `cidrs` key and
+				   hostnames ending in any of the domains in the `domain_suffixes` key. At least one of them
+				   is required.
+				* `otp_code`: Redacts 4 to 8 digit one-time codes close to phrases such as "code", "OTP" or
+				   "verification" within the same sentence, leaving other numbers intact.
+				* `base58_blob`: Redacts base58-encoded identifiers such as Bitcoin addresses or API keys, which
+				   use letters and digits except `0`, `O`, `I` and `l`. Only identifiers holding a digit and of
+				   at least 24 characters are redacted; the optional `min_length` key sets another length.
+				* `cookie`: Redacts cookie values in `Cookie` and `Set-Cookie` headers, including headers holding
+				   several cookies. Cookie names and `Set-Cookie` attributes such as `Path`, `Domain`, `Secure`,
+				   `HttpOnly` and `SameSite` are kept. The optional `names` key limits this to cookies whose name
+				   matches any of the given patterns.
+				* `trace_header`: Redacts the values of headers carrying request, trace or internal user IDs,
+				   such as `X-Request-ID`, `X-Trace-ID`, `traceparent` or `X-Internal-User`, keeping the header
+				   names. Header names are matched regardless of case and can be replaced through the optional
+				   `names` key.
+				* `iac_secret`: Redacts secrets in infrastructure-as-code output such as Terraform state or
+				   Ansible runs: the bodies of Ansible vaults, including `!vault |` blocks, HashiCorp Vault
+				   ciphertexts such as `vault:v1:...`, and the values following the markers in the optional
+				   `markers` key, which defaults to `!vault` and `sensitive_attribute`.
+				* `device_fingerprint`: Redacts browser and device fingerprints, such as canvas, WebGL or audio
+				   hashes, in `key=value` pairs, JSON and object fields. By default, values are redacted if
+				   their key matches common fingerprint names and if they look like a long hex or base64 hash.
+				   Given the optional `patterns` key, values are redacted if their key matches any of the
+				   patterns, and given the optional `shapes` key, if they match any of the shapes, either being
+				   enough if both are given.
+				* `command_line_arg`: Redacts the values following flags such as `--password`, `--token` or
+				   `-p` in command lines, given as `--flag value`, `--flag=value` or `-p value`. The flags can be
+				   replaced through the optional `flags` key. A flag followed by another flag such as `--verbose`
+				   has no value, but dashed words with digits or symbols such as `-x9secret` are redacted as
+				   values.
+				* `street_address`: Redacts US-style street addresses made of a house number, a capitalized
+				   street name and a street type such as `St`, `Ave`, `Rd` or `Blvd`, along with a following
+				   unit, city and state, and ZIP code if present. Addresses without a street type aren't
+				   matched, to avoid redacting prose.
+				* `proto_path`: Redacts the values of fields at any of the protobuf field paths in the `paths`
+				   key, which is required, such as `user.payment.card_number`, in messages dumped as JSON or in
+				   the protobuf text format. Field names match regardless of case and underscores, so
+				   `card_number` also matches the JSON name `cardNumber`. Paths match the end of field paths,
+				   so prefixes printed before a message don't matter.
+				* `email_header`: Redacts the values of email headers carrying secrets, such as
+				   `DKIM-Signature`, `Authorization` or `X-API-Key`, keeping the header names. Folded headers are
+				   redacted line by line, keeping the folding. The header names can be replaced through the
+				   optional `names` key.
+				* `referer_secret`: Redacts secrets in `Referer` URLs and `User-Agent` strings, such as the
+				   values of `token`, `api_key` or `sig` parameters and opaque tokens as matched by
+				   `base58_blob`.
+				* `jwt_cookie`: Redacts JSON Web Tokens stored in cookies commonly holding them, such as
+				   `jwt`, `access_token` or `id_token`, in `Cookie` and `Set-Cookie` headers. Values of these
+				   cookies that aren't JWTs are left intact. The cookie names can be replaced through the
+				   optional `names` key, a list of patterns.
+				* `syslog_structured_data`: Redacts the values of parameters in the RFC 5424 structured data
+				   of syslog messages, such as `password` in `[auth@32473 password="..."]`. The parameter
+				   names are given through the required `keys` key. Values with escaped quotes are handled,
+				   and the quotes around values are kept.
+				* `basic_auth`: Redacts the base64-encoded credentials of HTTP Basic authentication, such as
+				   `dXNlcjpwYXNz` in `Authorization: Basic dXNlcjpwYXNz`, keeping the scheme. Only text
+				   decoding to `user:password` credentials is redacted, leaving other base64 alone.
+				* `metric_label`: Redacts the values of metric labels, either in the Prometheus text format,
+				   such as `api_key` in `requests_total{api_key="..."} 1`, or in DogStatsD tags, such as
+				   `requests:1|c|#api_key:...`, keeping the metric name and all other labels. The label names
+				   are given through the required `names` key.
+				* `rpc_metadata`: Redacts in full the strings of object fields with keys of RPC metadata and
+				   trailers commonly carrying secrets, such as `authorization`, `cookie` or `x-api-key`,
+				   ignoring case. Strings in arrays are matched by the key of the field holding the array. The
+				   keys can be replaced through the optional `keys` key. Numbers, objects and other values
+				   of these fields are replaced whole by a string.
+				* `field`: Redacts in full the strings of object fields with any of the keys given through the
+				   required `keys` key, at any depth and regardless of their contents, such as `password` or
+				   `authorization`. Keys are compared exactly, and strings in arrays are matched by the key of
+				   the field holding the array. Numbers, objects and other values of these fields are
+				   replaced whole by a string.
+
+				Any of these names can also be an alias registered by the host application, such as `pan`
+				for `credit_card`.
+
+				See examples for more details.
+				"""#
+			required:    true
+			type: ["array"]
+		},
+		{
+			name: "redactor"
+			description: """
+				How matches are replaced, either as a redactor name or as an object with a `type` key naming
+				the redactor. Redactors can be:
+
+				* `full`: Replaces matches with `[REDACTED]`.
+				* `placeholder`: Replaces matches with a word describing the filter that matched, such as
+				   `<card number>`, to keep redacted prose readable. `pattern` filters can name their
+				   matches through the optional `placeholder` key, and otherwise use `<redacted>`.
+				* `labeled_hash`: Replaces matches with the name of the filter that matched and a short
+				   SHA-256 hash of the match, such as `[CREDIT_CARD:90c348]`, so redacted values can be
+				   correlated across events. The optional `length` key sets the number of hex digits of the
+				   hash, 6 by default, and the optional `hex_case` key sets the case of the hex digits, either
+				   `lower`, the default, or `upper`. `pattern` filters are labeled with their `placeholder` if
+				   set.
+				* `synthetic`: Replaces the letters and digits of matches with random ones, keeping their
+				   case, length and all other characters, to produce realistic but fake data. The random
+				   characters are derived from `seed` and the match, so the same event is always redacted
+				   the same way while events with different seeds differ.
+				* `fixed_mask`: Replaces matches with the same number of mask characters regardless of their
+				   length, such as `********`, so the length of sensitive data isn't revealed either. The
+				   optional `length` key sets the number of characters, 8 by default, and the optional `char`
+				   key sets the mask character, `*` by default.
+				* `mask`: Replaces every character of matches with a mask character, such as sixteen `*` for a
+				   card number, keeping the width of fields parsers depend on. The optional `char` key sets
+				   the mask character, `*` by default.
+				* `field_key`: Replaces matches with `[REDACTED:<key>]`, naming the key of the object field
+				   holding them, such as `[REDACTED:password]`, so reviewers can tell which field was
+				   scrubbed. Strings in arrays use the key of the field holding the array, and strings outside
+				   of objects are replaced with `[REDACTED]`.
+				* `partial`: Replaces all but the last characters of matches with `[REDACTED]`, such as
+				   `[REDACTED]4782` for a card number, so support agents can confirm identities. The
+				   optional `visible` key sets the number of characters to keep, 4 by default. Matches no
+				   longer than that are replaced with `[REDACTED]` in full.
+				* `sha256`: Replaces matches with the hex-encoded SHA-256 hash of the match, so the same value
+				   always maps to the same token and events can be correlated without exposing it. The
+				   optional `prefix` key sets text to prepend to hashes, such as `sha256:`, and the optional
+				   `hex_case` key sets the case of the hex digits, either `lower`, the default, or `upper`.
+				* `token`: Replaces matches with numbered tokens, such as `[REDACTED_1]` for the first distinct
+				   match and `[REDACTED_2]` for the second, reusing the token of every repeated match across
+				   all filters and fields of `value`. Analysts can then tell which redacted values are the same
+				   without computing hashes. Numbers only hold within a single call, and objects are always
+				   redacted sequentially, regardless of `parallel_threshold`.
+
+				Any other string is used verbatim as the replacement, such as `***` or `<scrubbed>`, for
+				downstream systems that can't handle `[REDACTED]`.
+				"""
+			required: false
+			default:  "full"
+			type: ["string", "object"]
+		},
+		{
+			name: "preserve_punctuation"
+			description: """
+				Whether to keep leading and trailing characters that are neither letters nor digits out of
+				each match, so that text such as `secret.` is redacted as `[REDACTED].`.
+				"""
+			required: false
+			default:  false
+			type: ["boolean"]
+		},
+		{
+			name: "max_output_len"
+			description: """
+				The maximum number of characters of each redacted string. Longer strings are truncated and
+				suffixed with `…[truncated]`. Truncation never splits a character or a replacement. Binary
+				data scanned through `scan_binary` is limited to this many bytes as a whole.
+				"""
+			required: false
+			type: ["integer"]
+		},
+		{
+			name: "parallel_threshold"
+			description: """
+				Redact the fields of objects with more than this many fields in parallel. The result is the
+				same as when redacting sequentially. By default, fields are redacted sequentially.
+				"""
+			required: false
+			type: ["integer"]
+		},
+		{
+			name: "sample_rate"
+			description: """
+				The fraction of calls, between `0.0` and `1.0`, for which the filters are run. All other
+				calls return `value` unchanged. This trades completeness for throughput: sensitive data in
+				calls that are not sampled is not redacted.
+				"""
+			required: false
+			default:  1.0
+			type: ["float"]
+		},
+		{
+			name: "output"
+			description: """
+				What to return. `value` returns the redacted value. `diff` returns an object with the redacted
+				value in `value` and the applied replacements in `diff`, each with the byte `offset` and
+				`original_len` of the replaced text, its `replacement` and, for strings nested in `value`,
+				their `path`. Truncation through `max_output_len` isn't part of the diff. `counts` returns an
+				object with the redacted value in `value` and the number of redactions per filter name in
+				`counts`, so a single call can both scrub a value and feed metrics. `count` returns an object
+				with the redacted value in `value` and the total number of redactions across all filters in
+				`count`, for audit dashboards. `original` returns an object with the redacted value in
+				`value` and the original value in `original`, with every string replaced by the hex-encoded
+				SHA-256 hash of its contents, and every other value but `null` by the hash of its string
+				representation, so it can be kept in a companion field such as
+				`.message_original` without exposing the sensitive data.
+				`bytes_redacted` returns an object with the redacted value in `value` and the total length in
+				bytes of the replaced text across all of `value` in `bytes_redacted`, to quantify how much
+				sensitive data passes through. `report` returns an object with the redacted value in `value`
+				and a summary in `report`, holding the names of the filters that matched in `filters_fired`,
+				the number of redactions per filter name in `counts`, the total length in bytes of the
+				replaced text in `bytes_redacted` and, as an advisory to tune filters, the number of tokens no
+				filter matched that look like secrets in `possible_missed_secrets`. These are runs of at
+				least 12 digits, and tokens of at least 20 characters mixing letters and digits with high
+				entropy. Its `quarantined` flag tells whether `quarantine_fields` fired, also under
+				`dry_run`, where the value is left as is.
+				"""
+			required: false
+			default:  "value"
+			type: ["string"]
+			enum: {
+				value:          "Return the redacted value."
+				diff:           "Return the redacted value along with the applied replacements."
+				counts:         "Return the redacted value along with the number of redactions per filter."
+				count:          "Return the redacted value along with the total number of redactions."
+				original:       "Return the redacted value along with the hashed original value."
+				bytes_redacted: "Return the redacted value along with the number of bytes replaced."
+				report:         "Return the redacted value along with a summary of the redactions."
+			}
+		},
+		{
+			name: "quoted"
+			description: """
+				Which matches to redact depending on whether they are inside quotes. Useful for structured
+				logs, where quoted values are often the real data and the surrounding text is a template.
+				Double and single quotes are recognized and can be escaped with a backslash.
+				"""
+			required: false
+			default:  "any"
+			type: ["string"]
+			enum: {
+				any:     "Redact all matches."
+				inside:  "Only redact matches within quotes."
+				outside: "Only redact matches outside of quotes."
+			}
+		},
+		{
+			name: "overlap_strategy"
+			description: """
+				How to resolve matches that overlap, whether found by the same filter or by different ones.
+				"""
+			required: false
+			default:  "merge"
+			type: ["string"]
+			enum: {
+				merge:    "Redact the union of overlapping matches as a single match."
+				first:    "Redact the match starting first, or the longest of those starting at the same position, leaving the rest of the other matches intact."
+				longest:  "Redact the longest match, or the one starting first of those equally long, leaving the rest of the other matches intact."
+				priority: "Redact the match of the filter listed first in `filters`, breaking ties as `first` does, leaving the rest of the other matches intact."
+			}
+		},
+		{
+			name: "seed"
+			description: """
+				The seed of the `synthetic` redactor, such as an event ID. Matches are replaced the same way
+				for the same seed. Without a seed, matches are replaced the same way across all events.
+				"""
+			required: false
+			type: ["string"]
+		},
+		{
+			name: "decode_percent"
+			description: """
+				Whether to match filters against the percent-decoded form of strings, such as
+				`jane.doe@example.com` for `jane.doe%40example.com`, to catch sensitive data in URLs.
+				Matches are redacted in full while all other text keeps its encoding. Malformed escapes and
+				escapes that don't decode to valid UTF-8 are matched as they are.
+				"""
+			required: false
+			default:  false
+			type: ["boolean"]
+		},
+		{
+			name: "quarantine_fields"
+			description: """
+				Top-level fields that quarantine the whole record. If `value` is an object in which any of
+				these fields is set to something other than `null` or an empty string, array or object, it
+				is replaced with `{"quarantined": true}` instead of being partially redacted. Must be a
+				literal array of strings.
+				"""
+			required: false
+			type: ["array"]
+		},
+		{
+			name: "min_occurrences"
+			description: """
+				Only redact matched text that occurs at least this many times in the string it was found
+				in, such as a token reused across a request. By default, all matches are redacted.
+				"""
+			required: false
+			type: ["integer"]
+		},
+		{
+			name: "ignore_values"
+			description: """
+				Strings that are never redacted, such as `N/A` or `-` placeholders that happen to match a
+				filter. Strings equal to any of these are returned unchanged without running the filters.
+				Must be a literal array of strings.
+				"""
+			required: false
+			type: ["array"]
+		},
+		{
+			name: "preserve_json"
+			description: """
+				Keep serialized JSON in strings valid. Redactions within JSON strings are escaped, and
+				redacted numbers and other bare values are replaced with quoted strings.
+				"""
+			required: false
+			default:  false
+			type: ["boolean"]
+		},
+		{
+			name: "list_delimiter"
+			description: """
+				A delimiter, such as `,` or a newline, separating the elements of strings holding lists.
+				Filters are matched against each element on its own, so a match never spans several
+				elements and the delimiters are always kept. Must be a string literal.
+				"""
+			required: false
+			type: ["string"]
+		},
+		{
+			name: "dry_run"
+			description: """
+				Whether to return `value` unchanged while still recording the redactions that would have
+				been applied in the `diff`, `counts`, `count`, `bytes_redacted` or `report` outputs, to
+				validate filters against production data before enabling them. `quarantine_fields` are not
+				applied either.
+				"""
+			required: false
+			default:  false
+			type: ["boolean"]
+		},
+		{
+			name: "scan_binary"
+			description: """
+				Whether to redact strings holding binary data, which aren't valid UTF-8, by only matching
+				filters against their runs of at least four printable ASCII characters, such as API keys
+				embedded in binary payloads. All other bytes are kept unchanged, where they would otherwise
+				be replaced with `�`.
+				"""
+			required: false
+			default:  false
+			type: ["boolean"]
+		},
+		{
+			name: "coerce_scalars"
+			description: """
+				Whether to also redact integers and floats, such as card numbers parsed into numeric
+				fields, by matching filters against their string representation. Numbers with any match
+				are replaced with the redacted string, and all other numbers keep their type.
+				"""
+			required: false
+			default:  false
+			type: ["boolean"]
+		},
+		{
+			name: "allowlist"
+			description: """
+				Matches that are never redacted, such as the test card number `4111111111111111` or known
+				safe internal IDs. A match is kept if it's equal to any of the strings, or entirely matched
+				by any of the regular expressions, while other matches in the same string are still
+				redacted. Must be a literal array of strings and regular expressions.
+				"""
+			required: false
+			type: ["array"]
+		},
+	]
+	internal_failure_reasons: []
+	return: {
+		types: ["any"]
+		rules: [
+			"Returns a value of the same type as `value`, unless `coerce_scalars` redacts numbers to strings, or `output` is `diff`, `counts`, `count`, `original`, `bytes_redacted` or `report`.",
+			"Returns `{\"quarantined\": true}` in place of `value` if any of the `quarantine_fields` is set.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Replace text using a regex"
+			source: #"""
+				redact("my id is 123456", filters: [r'\d+'])
+				"""#
+			return: "my id is [REDACTED]"
+		},
+		{
+			title: "Replace credit card numbers in any field"
+			source: #"""
+				redact({ "name": "John Doe", "card_number": "4916155524184782"}, filters: ["credit_card"])
+				"""#
+			return: {
+				name:        "John Doe"
+				card_number: "[REDACTED]"
+			}
+		},
+		{
+			title: "Replace the value of an inline secret"
+			source: #"""
+				redact("the password is hunter2", filters: ["inline_secret_phrase"])
+				"""#
+			return: "the password is [REDACTED]"
+		},
+		{
+			title: "Replace matches with custom text"
+			source: #"""
+				redact("my id is 123456", filters: [r'\d+'], redactor: "***")
+				"""#
+			return: "my id is ***"
+		},
+		{
+			title: "Replace names with a readable placeholder"
+			source: #"""
+				redact("ticket opened by Alice Smith", filters: [{"type": "pattern", "patterns": ["Alice Smith"], "placeholder": "name"}], redactor: "placeholder")
+				"""#
+			return: "ticket opened by <name>"
+		},
+		{
+			title: "Return the applied replacements"
+			source: #"""
+				redact("my id is 123456", filters: [r'\d+'], output: "diff")
+				"""#
+			return: {
+				value: "my id is [REDACTED]"
+				diff: [{offset: 9, original_len: 6, replacement: "[REDACTED]"}]
+			}
+		},
+		{
+			title: "Count the redactions per filter"
+			source: #"""
+				redact("card 4916155524184782", filters: ["credit_card"], output: "counts")
+				"""#
+			return: {
+				value: "card [REDACTED]"
+				counts: credit_card: 1
+			}
+		},
+		{
+			title: "Report the redactions"
+			source: #"""
+				redact("card 4916155524184782, code is 4821", filters: ["credit_card", "otp_code"], output: "report")
+				"""#
+			return: {
+				value: "card [REDACTED], code is [REDACTED]"
+				report: {
+					filters_fired: ["credit_card", "otp_code"]
+					counts: {credit_card: 1, otp_code: 1}
+					bytes_redacted: 20
+					possible_missed_secrets: 0
+					quarantined:             false
+				}
+			}
+		},
+		{
+			title: "Keep a hashed copy of the original value"
+			source: #"""
+				redact("my id is 123456", filters: [r'\d+'], output: "original")
+				"""#
+			return: {
+				value:    "my id is [REDACTED]"
+				original: "14e62bdec2fce886e34e2593fd6f407149bb527333283a45b93a38ef3e86a6c8"
+			}
+		},
+		{
+			title: "Cap the length of the redacted output"
+			source: #"""
+				redact("my id is 123456", filters: [r'\d+'], max_output_len: 5)
+				"""#
+			return: "my id…[truncated]"
+		},
+	]
+}
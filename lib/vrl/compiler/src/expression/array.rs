@@ -1,6 +1,7 @@
 use crate::expression::{Expr, Resolved};
 use crate::{Context, Expression, State, TypeDef, Value};
 use std::fmt;
+use std::ops::Deref;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Array {
@@ -11,6 +12,18 @@ impl Array {
     pub(crate) fn new(inner: Vec<Expr>) -> Self {
         Self { inner }
     }
+
+    pub fn into_inner(self) -> Vec<Expr> {
+        self.inner
+    }
+}
+
+impl Deref for Array {
+    type Target = Vec<Expr>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
 }
 
 impl Expression for Array {
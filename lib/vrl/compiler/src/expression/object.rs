@@ -2,6 +2,7 @@ use crate::expression::{Expr, Resolved};
 use crate::{Context, Expression, State, TypeDef, Value};
 use std::collections::BTreeMap;
 use std::fmt;
+use std::ops::Deref;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Object {
@@ -14,6 +15,14 @@ impl Object {
     }
 }
 
+impl Deref for Object {
+    type Target = BTreeMap<String, Expr>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
 impl Expression for Object {
     fn resolve(&self, ctx: &mut Context) -> Resolved {
         self.inner
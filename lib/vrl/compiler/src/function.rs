@@ -1,4 +1,4 @@
-use crate::expression::{Expr, Expression, FunctionArgument, Literal, Query};
+use crate::expression::{Container, Expr, Expression, FunctionArgument, Literal, Query, Variant};
 use crate::parser::Node;
 use crate::value::Kind;
 use crate::{Span, Value};
@@ -190,6 +190,25 @@ impl ArgumentList {
         Ok(required(self.optional_regex(keyword)?))
     }
 
+    pub fn optional_array(&mut self, keyword: &'static str) -> Result<Option<Vec<Expr>>, Error> {
+        self.optional_expr(keyword)
+            .map(|expr| match expr {
+                Expr::Container(Container {
+                    variant: Variant::Array(array),
+                }) => Ok(array.into_inner()),
+                expr => Err(Error::UnexpectedExpression {
+                    keyword,
+                    expected: "array",
+                    expr,
+                }),
+            })
+            .transpose()
+    }
+
+    pub fn required_array(&mut self, keyword: &'static str) -> Result<Vec<Expr>, Error> {
+        Ok(required(self.optional_array(keyword)?))
+    }
+
     pub(crate) fn keywords(&self) -> Vec<&'static str> {
         self.0.keys().copied().collect::<Vec<_>>()
     }
@@ -198,7 +217,7 @@ impl ArgumentList {
         self.0.insert(k, v);
     }
 
-    fn optional_expr(&mut self, keyword: &'static str) -> Option<Expr> {
+    pub fn optional_expr(&mut self, keyword: &'static str) -> Option<Expr> {
         self.0.remove(keyword)
     }
 
@@ -256,6 +275,13 @@ pub enum Error {
         value: Value,
         variants: Vec<Value>,
     },
+
+    #[error("invalid argument: {error}")]
+    InvalidArgument {
+        keyword: &'static str,
+        error: &'static str,
+        expr: Expr,
+    },
 }
 
 impl diagnostic::DiagnosticError for Error {
@@ -265,6 +291,7 @@ impl diagnostic::DiagnosticError for Error {
         match self {
             UnexpectedExpression { .. } => 400,
             InvalidEnumVariant { .. } => 401,
+            InvalidArgument { .. } => 402,
         }
     }
 
@@ -307,6 +334,19 @@ impl diagnostic::DiagnosticError for Error {
                     Span::default(),
                 ),
             ],
+
+            InvalidArgument {
+                keyword,
+                error,
+                expr,
+            } => vec![
+                Label::primary(
+                    format!(r#"invalid argument "{}""#, keyword),
+                    Span::default(),
+                ),
+                Label::context(format!("received: {}", expr.as_str()), Span::default()),
+                Label::context(format!("error: {}", error), Span::default()),
+            ],
         }
     }
 }
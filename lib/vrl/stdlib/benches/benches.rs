@@ -73,8 +73,7 @@ criterion_group!(
               parse_tokens,
               parse_url,
               push,
-              // TODO: Has not been ported to vrl/stdlib yet
-              //redact,
+              redact,
               replace,
               round,
               sha1,
@@ -1162,19 +1161,33 @@ bench_function! {
     }
 }
 
-//bench_function! {
-//redact => vrl_stdlib::Redact;
+bench_function! {
+    redact => vrl_stdlib::Redact;
+
+    regex {
+        args: func_args![
+            value: "hello 123456 world",
+            filters: vec![Regex::new(r"\d+").unwrap()],
+        ],
+        want: Ok("hello [REDACTED] world"),
+    }
+
+    credit_card {
+        args: func_args![
+            value: "hello 4916155524184782 world",
+            filters: vec!["credit_card"],
+        ],
+        want: Ok("hello [REDACTED] world"),
+    }
 
-//literal {
-//args: func_args![
-//value: "hello 1111222233334444",
-//filters: value!(["pattern"]),
-//patterns: value!(vec!(Regex::new(r"/[0-9]{16}/").unwrap())),
-//redactor: "full",
-//],
-//want: Ok("hello ****"),
-//}
-//}
+    no_match {
+        args: func_args![
+            value: "hello world, nothing to see here",
+            filters: vec![value!("credit_card"), value!(Regex::new(r"\d+").unwrap())],
+        ],
+        want: Ok("hello world, nothing to see here"),
+    }
+}
 
 bench_function! {
     replace => vrl_stdlib::Replace;
@@ -144,8 +144,8 @@ mod parse_tokens;
 mod parse_url;
 #[cfg(feature = "push")]
 mod push;
-//#[cfg(feature = "redact")]
-//mod redact;
+#[cfg(feature = "redact")]
+mod redact;
 #[cfg(feature = "parse_query_string")]
 mod parse_query_string;
 #[cfg(feature = "replace")]
@@ -345,8 +345,8 @@ pub use parse_url::ParseUrl;
 pub use push::Push;
 #[cfg(feature = "match")]
 pub use r#match::Match;
-//#[cfg(feature = "redact")]
-//pub use redact::Redact;
+#[cfg(feature = "redact")]
+pub use redact::{register_filter_alias, Redact, RedactionEngine};
 #[cfg(feature = "replace")]
 pub use replace::Replace;
 #[cfg(feature = "round")]
@@ -544,8 +544,8 @@ pub fn all() -> Vec<Box<dyn vrl::Function>> {
         Box::new(Push),
         #[cfg(feature = "match")]
         Box::new(Match),
-        //#[cfg(feature = "redact")]
-        //Box::new(Redact),
+        #[cfg(feature = "redact")]
+        Box::new(Redact),
         #[cfg(feature = "replace")]
         Box::new(Replace),
         #[cfg(feature = "round")]